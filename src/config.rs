@@ -0,0 +1,137 @@
+//! User configuration loaded from a TOML file (`~/.config/clipzero/config.toml`
+//! on Linux). Every field is optional; a missing, unreadable, or malformed file
+//! falls back to the same defaults clipzero shipped with when these values were
+//! hard-coded.
+
+use global_hotkey::hotkey::{Code, HotKey, Modifiers};
+use iced::Theme;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Hotkey that opens the picker, e.g. `"SUPER+Digit0"`.
+    pub open_hotkey: String,
+    /// Maximum number of history entries to keep.
+    pub history_size: usize,
+    pub width: u32,
+    pub height: u32,
+    pub position_x: i32,
+    pub position_y: i32,
+    /// The color theme: `"light"` or `"dark"`.
+    pub theme: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            open_hotkey: String::from("SUPER+Digit0"),
+            history_size: crate::MAX_HISTORY,
+            width: 400,
+            height: 200,
+            position_x: 0,
+            position_y: 0,
+            theme: String::from("dark"),
+        }
+    }
+}
+
+impl Config {
+    /// Read the config file, falling back to defaults on any failure.
+    pub fn load() -> Self {
+        config_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// The parsed open hotkey, falling back to `SUPER+Digit0` if the spec is
+    /// invalid.
+    pub fn hotkey(&self) -> HotKey {
+        parse_hotkey(&self.open_hotkey)
+            .unwrap_or_else(|| HotKey::new(Some(Modifiers::SUPER), Code::Digit0))
+    }
+
+    /// The configured theme. Only `"light"` and `"dark"` are recognised;
+    /// anything else (including a missing or misspelled value) falls back to
+    /// `Theme::Dark`.
+    pub fn theme(&self) -> Theme {
+        match self.theme.to_lowercase().as_str() {
+            "light" => Theme::Light,
+            _ => Theme::Dark,
+        }
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    let mut dir = dirs::config_dir()?;
+    dir.push("clipzero");
+    dir.push("config.toml");
+    Some(dir)
+}
+
+/// Parse a `"MOD+MOD+Code"` spec into a [`HotKey`]. Modifier tokens are
+/// case-insensitive; the remaining token is parsed as a `keyboard_types::Code`
+/// (e.g. `Digit0`, `KeyV`).
+fn parse_hotkey(spec: &str) -> Option<HotKey> {
+    let mut mods = Modifiers::empty();
+    let mut code = None;
+    for part in spec.split('+') {
+        let part = part.trim();
+        match part.to_uppercase().as_str() {
+            "SUPER" | "CMD" | "META" | "WIN" => mods |= Modifiers::SUPER,
+            "CTRL" | "CONTROL" => mods |= Modifiers::CONTROL,
+            "ALT" | "OPTION" => mods |= Modifiers::ALT,
+            "SHIFT" => mods |= Modifiers::SHIFT,
+            _ => code = part.parse::<Code>().ok(),
+        }
+    }
+    code.map(|code| {
+        let mods = (!mods.is_empty()).then_some(mods);
+        HotKey::new(mods, code)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_modifier_and_code() {
+        let hotkey = parse_hotkey("SUPER+Digit0").expect("valid spec");
+        assert_eq!(hotkey.mods, Modifiers::SUPER);
+        assert_eq!(hotkey.key, Code::Digit0);
+    }
+
+    #[test]
+    fn modifier_tokens_are_case_insensitive_and_combine() {
+        let hotkey = parse_hotkey("ctrl+Shift+KeyV").expect("valid spec");
+        assert!(hotkey.mods.contains(Modifiers::CONTROL));
+        assert!(hotkey.mods.contains(Modifiers::SHIFT));
+        assert_eq!(hotkey.key, Code::KeyV);
+    }
+
+    #[test]
+    fn code_only_spec_has_no_modifiers() {
+        let hotkey = parse_hotkey("KeyA").expect("valid spec");
+        assert_eq!(hotkey.mods, Modifiers::empty());
+        assert_eq!(hotkey.key, Code::KeyA);
+    }
+
+    #[test]
+    fn invalid_spec_returns_none() {
+        assert!(parse_hotkey("SUPER+SHIFT").is_none());
+        assert!(parse_hotkey("not-a-code").is_none());
+        assert!(parse_hotkey("").is_none());
+    }
+
+    #[test]
+    fn theme_falls_back_to_dark_for_unknown() {
+        let mut config = Config::default();
+        config.theme = String::from("solarized");
+        assert!(matches!(config.theme(), Theme::Dark));
+        config.theme = String::from("light");
+        assert!(matches!(config.theme(), Theme::Light));
+    }
+}
@@ -0,0 +1,16 @@
+//! Best-effort reader for the clipboard's `text/html` flavor.
+//!
+//! `arboard` can *write* HTML (`set_html`) but never hands it back on a read,
+//! so the rich format copied from a browser, Excel, or an editor would be lost
+//! the moment it entered our history. We reach past `arboard` to the platform
+//! clipboard just for that one format; every failure degrades to `None` so the
+//! capture layer falls back to text-only exactly as before.
+
+use clipboard_rs::{Clipboard, ClipboardContext};
+
+/// Read the current clipboard's HTML representation, if any. Returns `None`
+/// when no HTML flavor is present or the platform clipboard can't be reached.
+pub fn read_html() -> Option<String> {
+    let ctx = ClipboardContext::new().ok()?;
+    ctx.get_html().ok().filter(|html| !html.is_empty())
+}
@@ -1,27 +1,82 @@
-use arboard::Clipboard;
+use arboard::{Clipboard, ImageData};
 use clipboard_master::{CallbackResult, ClipboardHandler, Master};
 use crossbeam_channel::{unbounded, Receiver};
-use global_hotkey::{
-    hotkey::{Code, HotKey, Modifiers},
-    GlobalHotKeyEvent, GlobalHotKeyManager,
-};
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager};
 use iced::{
     keyboard::{Event as KeyEvent, KeyCode},
     subscription,
     window::{Level, PlatformSpecific},
     Application, Command, Element, Font, Length, Settings, Subscription, Theme,
 };
-use std::{collections::VecDeque, sync::Arc, thread};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+mod config;
+mod html;
+mod persistence;
+
+use config::Config;
+
+const MAX_HISTORY: usize = 100;
+
+/// How long rapid clipboard updates are coalesced before a disk write.
+const PERSIST_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// A single clipboard history entry holding every format we managed to capture
+/// for one copy. Plain text is kept verbatim, `html` preserves rich content
+/// (Excel cells, formatted web selections, ...), and images are kept as owned
+/// RGBA8 `ImageData` so they outlive the clipboard read that produced them.
+/// Any format the backend can't read is simply left `None`.
+#[derive(Debug, Clone, Default)]
+struct ClipboardPayload {
+    text: Option<String>,
+    html: Option<String>,
+    image: Option<ImageData<'static>>,
+}
 
-const MAX_HISTORY: usize = 10;
+impl ClipboardPayload {
+    fn is_empty(&self) -> bool {
+        self.text.is_none() && self.html.is_none() && self.image.is_none()
+    }
+
+    /// Entries de-duplicate on their primary content: images by their raw RGBA
+    /// bytes, otherwise plain text by equality. `html` is a companion format
+    /// captured from a separate backend, so it's left out of the comparison when
+    /// text or an image is present — two copies of the same text shouldn't split
+    /// into distinct entries just because one poll also read an HTML flavor and
+    /// another didn't. For html-only clips (no text, no image) `html` is the only
+    /// distinguishing content, so it is compared to avoid collapsing them all.
+    fn same_content(&self, other: &ClipboardPayload) -> bool {
+        match (&self.image, &other.image) {
+            (Some(a), Some(b)) => return a.bytes == b.bytes,
+            (None, None) => {}
+            _ => return false,
+        }
+        // Fall back to `html` only for html-only clips (no text, no image);
+        // otherwise distinct html-only entries would all collapse onto the
+        // first via `None == None`.
+        if self.text.is_none() && other.text.is_none() {
+            return self.html == other.html;
+        }
+        self.text == other.text
+    }
+}
 
 #[derive(Debug, Clone)]
 enum Message {
     ShowWindow,
     NumberPressed(usize),
-    ClipboardUpdated(String),
+    ClipboardUpdated(ClipboardPayload),
     CheckClipboard,
     ConfirmSelection,
+    PersistTick(u64),
+    SearchInput(String),
+    RegisterStore(char),
+    RegisterRecall(char),
     Hide,
     EventOccurred(iced::Event),
     ClipboardError(String),
@@ -39,57 +94,150 @@ impl ClipboardHandler for Handler {
 }
 
 struct ClipboardManager {
-    history: VecDeque<String>,
+    history: VecDeque<ClipboardPayload>,
+    registers: HashMap<char, ClipboardPayload>,
+    search_query: String,
+    /// History indices that match `search_query`, best-ranked first. With an
+    /// empty query this is just every entry in history order (newest first).
+    filtered: Vec<usize>,
+    /// Whether typed characters feed the search box (true) or act as
+    /// selection/register keys (false). Toggled with `Tab` while the window is
+    /// visible; the picker opens in search mode.
+    search_focused: bool,
     current_selection: Option<usize>,
     visible: bool,
     hotkey_receiver: Receiver<u8>,
     clipboard: Option<Clipboard>,
+    /// Monotonic counter used to debounce disk writes: only the tick carrying
+    /// the latest generation actually persists.
+    persist_gen: u64,
+    max_history: usize,
+    theme: Theme,
+}
+
+/// Flags handed to the iced `Application`: the hotkey channel plus the
+/// user-configured values resolved in `main`.
+struct Flags {
+    hotkey_receiver: Receiver<u8>,
+    max_history: usize,
+    theme: Theme,
 }
 
 impl ClipboardManager {
-    fn new(hotkey_receiver: Receiver<u8>) -> Self {
+    fn new(flags: Flags) -> Self {
+        let (history, registers) = persistence::load(flags.max_history);
         Self {
-            history: VecDeque::with_capacity(MAX_HISTORY),
+            history,
+            registers,
+            search_query: String::new(),
+            filtered: Vec::new(),
+            search_focused: false,
             current_selection: None,
             visible: false,
-            hotkey_receiver,
+            hotkey_receiver: flags.hotkey_receiver,
             clipboard: Clipboard::new().ok(),
+            persist_gen: 0,
+            max_history: flags.max_history,
+            theme: flags.theme,
         }
     }
 
-    fn add_to_history(&mut self, content: String) {
+    /// Schedule a debounced write of the current state. Rapid calls bump
+    /// `persist_gen` so only the final quiet tick reaches the disk.
+    fn schedule_save(&mut self) -> Command<Message> {
+        self.persist_gen = self.persist_gen.wrapping_add(1);
+        let generation = self.persist_gen;
+        Command::perform(
+            async move {
+                // Executor-agnostic: iced's executor isn't guaranteed to be
+                // tokio, so we avoid `tokio::time` and its reactor requirement.
+                futures_timer::Delay::new(PERSIST_DEBOUNCE).await;
+                generation
+            },
+            Message::PersistTick,
+        )
+    }
+
+    fn add_to_history(&mut self, content: ClipboardPayload) {
         for (i, entry) in self.history.clone().iter().enumerate() {
-            if content == *entry {
-                let e = self.history.remove(i);
-                self.history.push_front(e.unwrap());
+            if content.same_content(entry) {
+                // Drop the stale entry and promote the freshly-captured payload:
+                // same primary content may carry updated companion formats (new
+                // html, or differing text behind matching image bytes) that we
+                // want to keep rather than resurrect the old ones.
+                self.history.remove(i);
+                self.history.push_front(content);
                 return;
             }
         }
-        if self.history.len() >= MAX_HISTORY {
+        if self.history.len() >= self.max_history {
             self.history.pop_back();
         }
         self.history.push_front(content);
     }
 
+    /// Re-rank `filtered` against the current `search_query`. Entries missing a
+    /// query character (in order) are dropped; the rest sort by fuzzy score,
+    /// ties broken by recency (lower history index first).
+    fn recompute_filter(&mut self) {
+        let mut scored: Vec<(usize, i32)> = self
+            .history
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                fuzzy_score(&self.search_query, &searchable(entry)).map(|score| (i, score))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        self.filtered = scored.into_iter().map(|(i, _)| i).collect();
+    }
+
+    /// Map the current selection (an index into `filtered`) to a history index.
+    fn resolve_selection(&self) -> Option<usize> {
+        self.filtered.get(self.current_selection?).copied()
+    }
+
     fn check_clipboard(&mut self) -> Command<Message> {
         if let Some(clipboard) = &mut self.clipboard {
-            match clipboard.get_text() {
-                Ok(text) => {
-                    Command::perform(async move { Message::ClipboardUpdated(text) }, |msg| msg)
-                }
-                Err(e) => Command::perform(
-                    async move { Message::ClipboardError(e.to_string()) },
+            // Snapshot every format the backend will hand us. `arboard` reads
+            // text and images; HTML comes from the platform clipboard via
+            // `html::read_html`, which yields `None` when the flavor is absent.
+            let payload = ClipboardPayload {
+                text: clipboard.get_text().ok(),
+                html: html::read_html(),
+                image: clipboard.get_image().ok(),
+            };
+            if payload.is_empty() {
+                Command::perform(
+                    async move { Message::ClipboardError(String::from("no readable format")) },
                     |msg| msg,
-                ),
+                )
+            } else {
+                Command::perform(async move { Message::ClipboardUpdated(payload) }, |msg| msg)
             }
         } else {
             Command::none()
         }
     }
 
-    fn set_clipboard_content(&mut self, content: String) -> Command<Message> {
+    fn set_clipboard_content(&mut self, content: ClipboardPayload) -> Command<Message> {
         if let Some(clipboard) = &mut self.clipboard {
-            match clipboard.set_text(content) {
+            // Re-offer the richest format we stored, degrading gracefully: an
+            // image wins, otherwise html (with its plain-text alternative),
+            // otherwise bare text. `set_html` carries the text/plain companion,
+            // but `arboard::set_image` replaces the whole clipboard and exposes
+            // no way to attach a text flavor, so an image+text entry re-offers
+            // only the image — the text companion is lost on that path.
+            let result = if let Some(image) = content.image {
+                clipboard.set_image(image)
+            } else if let Some(html) = content.html {
+                clipboard.set_html(html, content.text)
+            } else if let Some(text) = content.text {
+                clipboard.set_text(text)
+            } else {
+                Ok(())
+            };
+            match result {
                 Ok(_) => Command::none(),
                 Err(e) => Command::perform(
                     async move { Message::ClipboardError(e.to_string()) },
@@ -102,11 +250,46 @@ impl ClipboardManager {
     }
 }
 
+/// Downsample an RGBA8 image to a small preview, scaling so the longest side is
+/// at most `max` pixels using nearest-neighbour sampling.
+fn thumbnail(image: &ImageData, max: usize) -> iced::widget::image::Handle {
+    let (w, h) = (image.width, image.height);
+    let longest = w.max(h).max(1);
+    let (tw, th) = if longest > max {
+        let scale = max as f32 / longest as f32;
+        (
+            ((w as f32 * scale) as usize).max(1),
+            ((h as f32 * scale) as usize).max(1),
+        )
+    } else {
+        (w.max(1), h.max(1))
+    };
+
+    let mut out = vec![0u8; tw * th * 4];
+    for y in 0..th {
+        for x in 0..tw {
+            let sx = x * w / tw;
+            let sy = y * h / th;
+            let si = (sy * w + sx) * 4;
+            let di = (y * tw + x) * 4;
+            if si + 4 <= image.bytes.len() {
+                out[di..di + 4].copy_from_slice(&image.bytes[si..si + 4]);
+            }
+        }
+    }
+    iced::widget::image::Handle::from_pixels(tw as u32, th as u32, out)
+}
+
+/// Stable id for the search box so `ShowWindow` can move keyboard focus onto it.
+fn search_input_id() -> iced::widget::text_input::Id {
+    iced::widget::text_input::Id::new("clipzero-search")
+}
+
 impl Application for ClipboardManager {
     type Theme = Theme;
     type Executor = iced::executor::Default;
     type Message = Message;
-    type Flags = Receiver<u8>;
+    type Flags = Flags;
 
     fn new(flags: Self::Flags) -> (Self, Command<Message>) {
         let mut manager = Self::new(flags);
@@ -115,7 +298,7 @@ impl Application for ClipboardManager {
     }
 
     fn theme(&self) -> Self::Theme {
-        Theme::Dark
+        self.theme.clone()
     }
 
     fn title(&self) -> String {
@@ -126,11 +309,15 @@ impl Application for ClipboardManager {
         match message {
             Message::ShowWindow => {
                 self.visible = true;
+                self.search_focused = true;
+                self.search_query.clear();
+                self.recompute_filter();
                 self.current_selection = Some(0);
                 Command::batch(vec![
                     self.check_clipboard(),
                     iced::window::gain_focus(),
                     iced::window::change_mode(iced::window::Mode::Windowed),
+                    iced::widget::text_input::focus(search_input_id()),
                 ])
             }
             Message::CheckClipboard => {
@@ -150,32 +337,100 @@ impl Application for ClipboardManager {
             }
             Message::ClipboardUpdated(content) => {
                 self.add_to_history(content);
+                self.recompute_filter();
+                self.schedule_save()
+            }
+            Message::PersistTick(generation) => {
+                if generation == self.persist_gen {
+                    persistence::save(&self.history, &self.registers);
+                }
+                Command::none()
+            }
+            Message::SearchInput(query) => {
+                // Only accept typed text while in search mode; in register/number
+                // mode the same key presses are handled in `EventOccurred` and
+                // must not leak into the query.
+                if self.search_focused {
+                    self.search_query = query;
+                    self.recompute_filter();
+                    self.current_selection = Some(0);
+                }
                 Command::none()
             }
             Message::ConfirmSelection => {
-                if let Some(index) = self.current_selection {
-                    if let Some(content) = self.history.get(index) {
+                if let Some(index) = self.resolve_selection() {
+                    if let Some(content) = self.history.get(index).cloned() {
                         self.visible = false;
                         return Command::batch(vec![
-                            self.set_clipboard_content(content.clone()),
+                            self.set_clipboard_content(content),
                             iced::window::change_mode(iced::window::Mode::Hidden),
                         ]);
                     }
                 }
                 Command::none()
             }
+            Message::RegisterStore(key) => {
+                if self.visible {
+                    if let Some(index) = self.resolve_selection() {
+                        if let Some(entry) = self.history.get(index).cloned() {
+                            self.registers.insert(key, entry);
+                            return self.schedule_save();
+                        }
+                    }
+                }
+                Command::none()
+            }
+            Message::RegisterRecall(key) => {
+                if let Some(entry) = self.registers.get(&key).cloned() {
+                    self.visible = false;
+                    return Command::batch(vec![
+                        self.set_clipboard_content(entry),
+                        iced::window::change_mode(iced::window::Mode::Hidden),
+                    ]);
+                }
+                Command::none()
+            }
             Message::EventOccurred(event) => {
                 if let iced::Event::Keyboard(key_event) = event {
                     match key_event {
-                        KeyEvent::KeyPressed { key_code, .. } => {
+                        KeyEvent::KeyPressed {
+                            key_code,
+                            modifiers,
+                        } => {
                             if self.visible {
                                 return match key_code {
                                     KeyCode::Escape => self.update(Message::Hide),
                                     KeyCode::Enter => self.update(Message::ConfirmSelection),
+                                    // Toggle between typing a query and using the
+                                    // keys for selection/registers.
+                                    KeyCode::Tab => {
+                                        self.search_focused = !self.search_focused;
+                                        if self.search_focused {
+                                            iced::widget::text_input::focus(search_input_id())
+                                        } else {
+                                            Command::none()
+                                        }
+                                    }
                                     _ => {
+                                        // In search mode the text box consumes the
+                                        // key; only in register/number mode do these
+                                        // select an entry or touch a register.
+                                        if self.search_focused {
+                                            return Command::none();
+                                        }
                                         if let Some(num) = key_code_to_number(key_code) {
                                             return self.update(Message::NumberPressed(num));
                                         }
+                                        if let Some(letter) = key_code_to_letter(key_code) {
+                                            // Shift+letter recalls a named register to the
+                                            // system clipboard; a bare letter stores the
+                                            // current selection into that register.
+                                            return if modifiers.shift() {
+                                                self.update(Message::RegisterRecall(letter))
+                                            } else {
+                                                self.update(Message::RegisterStore(letter))
+                                            };
+                                        }
                                         return Command::none();
                                     }
                                 };
@@ -216,31 +471,66 @@ impl Application for ClipboardManager {
     }
 
     fn view(&self) -> Element<Message> {
-        use iced::widget::{column, container, text};
+        use iced::widget::{column, container, image, text, text_input};
 
         let content = if self.visible {
             let mut items = column![].spacing(10).padding(20);
 
+            items = items.push(
+                text_input("Search...", &self.search_query)
+                    .id(search_input_id())
+                    .on_input(Message::SearchInput),
+            );
+            items = items.push(text(if self.search_focused {
+                "[search]  Tab: registers"
+            } else {
+                "[registers]  Tab: search"
+            }));
+
             if self.history.is_empty() {
                 items = items.push(text("No clipboard history yet"));
+            } else if self.filtered.is_empty() {
+                items = items.push(text("No matches."));
             }
 
-            let curr = self.current_selection.unwrap_or(0);
-            if curr > self.history.len()
-                || self
-                    .history
-                    .get(curr)
-                    .unwrap_or(&String::from(""))
-                    .is_empty()
-            {
-                items = items.push(text("Out of range of stored history."))
-            } else {
-                let mut content = self.history.get(curr).expect("help").clone();
-                if content.chars().count() > 100 {
-                    content = content.chars().take(100).collect();
-                    content.push_str("...");
+            match self.resolve_selection().and_then(|i| self.history.get(i)) {
+                Some(payload) if payload.image.is_some() => {
+                    items = items.push(image(thumbnail(payload.image.as_ref().unwrap(), 100)));
+                }
+                Some(payload) if payload.text.as_deref().is_some_and(|t| !t.is_empty()) => {
+                    let mut content = payload.text.clone().unwrap();
+                    if content.chars().count() > 100 {
+                        content = content.chars().take(100).collect();
+                        content.push_str("...");
+                    }
+                    items = items.push(text(content));
+                }
+                _ => {}
+            }
+
+            // The filtered list, numbered so the digit keys pick among the
+            // currently-displayed results.
+            let selected = self.current_selection.unwrap_or(0);
+            for (pos, &hist_index) in self.filtered.iter().take(10).enumerate() {
+                if let Some(entry) = self.history.get(hist_index) {
+                    let marker = if pos == selected { ">" } else { " " };
+                    let digit = (pos + 1) % 10;
+                    items = items.push(text(format!(
+                        "{} {}. {}",
+                        marker,
+                        digit,
+                        entry_preview(entry)
+                    )));
+                }
+            }
+
+            if !self.registers.is_empty() {
+                let mut keys: Vec<&char> = self.registers.keys().collect();
+                keys.sort();
+                for key in keys {
+                    let preview = entry_preview(&self.registers[key]);
+                    items = items.push(text(format!("\"{}: {}", key, preview)));
                 }
-                items = items.push(text(content));
             }
 
             items
@@ -273,7 +563,104 @@ fn key_code_to_number(key_code: KeyCode) -> Option<usize> {
     }
 }
 
+fn key_code_to_letter(key_code: KeyCode) -> Option<char> {
+    match key_code {
+        KeyCode::A => Some('a'),
+        KeyCode::B => Some('b'),
+        KeyCode::C => Some('c'),
+        KeyCode::D => Some('d'),
+        KeyCode::E => Some('e'),
+        KeyCode::F => Some('f'),
+        KeyCode::G => Some('g'),
+        KeyCode::H => Some('h'),
+        KeyCode::I => Some('i'),
+        KeyCode::J => Some('j'),
+        KeyCode::K => Some('k'),
+        KeyCode::L => Some('l'),
+        KeyCode::M => Some('m'),
+        KeyCode::N => Some('n'),
+        KeyCode::O => Some('o'),
+        KeyCode::P => Some('p'),
+        KeyCode::Q => Some('q'),
+        KeyCode::R => Some('r'),
+        KeyCode::S => Some('s'),
+        KeyCode::T => Some('t'),
+        KeyCode::U => Some('u'),
+        KeyCode::V => Some('v'),
+        KeyCode::W => Some('w'),
+        KeyCode::X => Some('x'),
+        KeyCode::Y => Some('y'),
+        KeyCode::Z => Some('z'),
+        _ => None,
+    }
+}
+
+/// The text a history entry is matched against during search. Text entries use
+/// their full contents; anything else falls back to its list preview.
+fn searchable(entry: &ClipboardPayload) -> String {
+    match &entry.text {
+        Some(text) => text.clone(),
+        None => entry_preview(entry),
+    }
+}
+
+/// Score `text` as a fuzzy subsequence match for `query`, case-insensitively.
+/// Returns `None` when `text` is missing any query character in order, so
+/// non-matches drop out of the list entirely. Higher is better: contiguous
+/// runs and matches at word boundaries are rewarded.
+fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let q: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    let t: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+
+    let mut qi = 0;
+    let mut score = 0;
+    let mut prev_match: Option<usize> = None;
+    for (ti, &ch) in t.iter().enumerate() {
+        if qi >= q.len() {
+            break;
+        }
+        if ch == q[qi] {
+            score += 1;
+            if let Some(prev) = prev_match {
+                if prev + 1 == ti {
+                    score += 5;
+                }
+            }
+            if ti == 0 || !t[ti - 1].is_alphanumeric() {
+                score += 3;
+            }
+            prev_match = Some(ti);
+            qi += 1;
+        }
+    }
+
+    (qi == q.len()).then_some(score)
+}
+
+/// A short, single-line preview of an entry for rendering in lists.
+fn entry_preview(entry: &ClipboardPayload) -> String {
+    if let Some(img) = &entry.image {
+        return format!("[image {}x{}]", img.width, img.height);
+    }
+    if let Some(text) = &entry.text {
+        let mut preview: String = text.chars().take(40).collect();
+        if text.chars().count() > 40 {
+            preview.push_str("...");
+        }
+        return preview;
+    }
+    if entry.html.is_some() {
+        return String::from("[html]");
+    }
+    String::new()
+}
+
 fn main() -> iced::Result {
+    let config = Config::load();
+
     let manager = GlobalHotKeyManager::new().unwrap();
     let (tx, rx) = unbounded();
 
@@ -282,7 +669,7 @@ fn main() -> iced::Result {
     let arctx_event = Arc::clone(&arctx);
     let arctx_monitor = Arc::clone(&arctx);
 
-    let hotkey_open = HotKey::new(Some(Modifiers::SUPER), Code::Digit0);
+    let hotkey_open = config.hotkey();
     manager.register(hotkey_open).unwrap();
 
     thread::spawn(move || {
@@ -299,8 +686,8 @@ fn main() -> iced::Result {
     let settings = Settings {
         id: Some(String::from("clipzero")),
         window: iced::window::Settings {
-            size: (400, 200),
-            position: iced::window::Position::Specific(0, 0),
+            size: (config.width, config.height),
+            position: iced::window::Position::Specific(config.position_x, config.position_y),
             min_size: None,
             max_size: None,
             visible: false,
@@ -311,7 +698,11 @@ fn main() -> iced::Result {
             icon: None,
             platform_specific: PlatformSpecific::default(),
         },
-        flags: rx,
+        flags: Flags {
+            hotkey_receiver: rx,
+            max_history: config.history_size,
+            theme: config.theme(),
+        },
         default_font: Font::MONOSPACE,
         default_text_size: 20.0,
         antialiasing: false,
@@ -320,3 +711,99 @@ fn main() -> iced::Result {
 
     ClipboardManager::run(settings)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn missing_char_rejects() {
+        assert_eq!(fuzzy_score("xyz", "hello world"), None);
+        // Characters present but out of order still reject.
+        assert_eq!(fuzzy_score("ba", "abc"), None);
+    }
+
+    #[test]
+    fn contiguous_run_beats_scattered() {
+        let contiguous = fuzzy_score("cat", "category").unwrap();
+        let scattered = fuzzy_score("cat", "c_a_t").unwrap();
+        assert!(contiguous > scattered);
+    }
+
+    #[test]
+    fn word_boundary_is_rewarded() {
+        let boundary = fuzzy_score("w", "hello world").unwrap();
+        let mid_word = fuzzy_score("w", "awww").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        assert!(fuzzy_score("ABC", "abcdef").is_some());
+    }
+
+    fn text_payload(text: &str) -> ClipboardPayload {
+        ClipboardPayload {
+            text: Some(text.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn image_payload(bytes: Vec<u8>) -> ClipboardPayload {
+        ClipboardPayload {
+            image: Some(ImageData {
+                width: 1,
+                height: 1,
+                bytes: std::borrow::Cow::Owned(bytes),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn same_content_compares_text() {
+        assert!(text_payload("hi").same_content(&text_payload("hi")));
+        assert!(!text_payload("hi").same_content(&text_payload("bye")));
+    }
+
+    #[test]
+    fn same_content_ignores_html_for_matching_text() {
+        let plain = text_payload("hi");
+        let mut rich = text_payload("hi");
+        rich.html = Some(String::from("<b>hi</b>"));
+        assert!(plain.same_content(&rich));
+    }
+
+    #[test]
+    fn same_content_distinguishes_html_only_clips() {
+        let a = ClipboardPayload {
+            html: Some(String::from("<p>one</p>")),
+            ..Default::default()
+        };
+        let b = ClipboardPayload {
+            html: Some(String::from("<p>two</p>")),
+            ..Default::default()
+        };
+        assert!(a.same_content(&a.clone()));
+        assert!(!a.same_content(&b));
+    }
+
+    #[test]
+    fn same_content_compares_image_bytes_not_identity() {
+        let a = image_payload(vec![1, 2, 3, 4]);
+        let b = image_payload(vec![1, 2, 3, 4]);
+        let c = image_payload(vec![4, 3, 2, 1]);
+        assert!(a.same_content(&b));
+        assert!(!a.same_content(&c));
+    }
+
+    #[test]
+    fn same_content_rejects_image_vs_text() {
+        assert!(!image_payload(vec![0, 0, 0, 0]).same_content(&text_payload("hi")));
+    }
+}
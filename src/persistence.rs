@@ -0,0 +1,148 @@
+//! On-disk persistence for the clipboard history and named registers.
+//!
+//! State is serialized to a single file under the platform data directory
+//! (e.g. `~/.local/share/clipzero/history.bin` on Linux) so clips survive a
+//! restart. Images are stored as PNG blobs rather than raw RGBA to keep the
+//! file small; every other format round-trips verbatim.
+
+use crate::ClipboardPayload;
+use arboard::ImageData;
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, VecDeque},
+    io::Cursor,
+    path::PathBuf,
+};
+
+/// The full persisted state: history newest-first plus the named registers.
+#[derive(Default, Serialize, Deserialize)]
+struct StoredState {
+    history: Vec<StoredEntry>,
+    registers: Vec<(char, StoredEntry)>,
+}
+
+/// Serializable mirror of [`ClipboardPayload`]. `arboard::ImageData` isn't
+/// `serde`-friendly, so the image is kept as a PNG blob.
+#[derive(Serialize, Deserialize)]
+struct StoredEntry {
+    text: Option<String>,
+    html: Option<String>,
+    image: Option<Vec<u8>>,
+}
+
+impl From<&ClipboardPayload> for StoredEntry {
+    fn from(payload: &ClipboardPayload) -> Self {
+        Self {
+            text: payload.text.clone(),
+            html: payload.html.clone(),
+            image: payload.image.as_ref().and_then(encode_png),
+        }
+    }
+}
+
+impl From<StoredEntry> for ClipboardPayload {
+    fn from(entry: StoredEntry) -> Self {
+        Self {
+            text: entry.text,
+            html: entry.html,
+            image: entry.image.as_deref().and_then(decode_png),
+        }
+    }
+}
+
+fn encode_png(image: &ImageData) -> Option<Vec<u8>> {
+    let buffer =
+        image::RgbaImage::from_raw(image.width as u32, image.height as u32, image.bytes.to_vec())?;
+    let mut out = Cursor::new(Vec::new());
+    image::DynamicImage::ImageRgba8(buffer)
+        .write_to(&mut out, image::ImageFormat::Png)
+        .ok()?;
+    Some(out.into_inner())
+}
+
+fn decode_png(png: &[u8]) -> Option<ImageData<'static>> {
+    let rgba = image::load_from_memory_with_format(png, image::ImageFormat::Png)
+        .ok()?
+        .to_rgba8();
+    let (width, height) = (rgba.width() as usize, rgba.height() as usize);
+    Some(ImageData {
+        width,
+        height,
+        bytes: Cow::Owned(rgba.into_raw()),
+    })
+}
+
+/// The state file path, creating the parent directory if needed. Returns `None`
+/// if the platform data directory can't be determined.
+fn state_path() -> Option<PathBuf> {
+    let mut dir = dirs::data_dir()?;
+    dir.push("clipzero");
+    std::fs::create_dir_all(&dir).ok()?;
+    dir.push("history.bin");
+    Some(dir)
+}
+
+/// Write the current history and registers to disk, best-effort.
+pub fn save(history: &VecDeque<ClipboardPayload>, registers: &HashMap<char, ClipboardPayload>) {
+    let Some(path) = state_path() else {
+        return;
+    };
+    let state = StoredState {
+        history: history.iter().map(StoredEntry::from).collect(),
+        registers: registers
+            .iter()
+            .map(|(key, payload)| (*key, StoredEntry::from(payload)))
+            .collect(),
+    };
+    if let Ok(bytes) = bincode::serialize(&state) {
+        let _ = std::fs::write(path, bytes);
+    }
+}
+
+/// Load persisted state, trimming the history to `max_history`. Missing or
+/// corrupt files yield empty state rather than failing startup.
+pub fn load(max_history: usize) -> (VecDeque<ClipboardPayload>, HashMap<char, ClipboardPayload>) {
+    let mut history = VecDeque::with_capacity(max_history);
+    let mut registers = HashMap::new();
+
+    if let Some(bytes) = state_path().and_then(|path| std::fs::read(path).ok()) {
+        if let Ok(state) = bincode::deserialize::<StoredState>(&bytes) {
+            for entry in state.history.into_iter().take(max_history) {
+                history.push_back(entry.into());
+            }
+            for (key, entry) in state.registers {
+                registers.insert(key, entry.into());
+            }
+        }
+    }
+
+    (history, registers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn png_round_trip_preserves_pixels() {
+        let bytes: Vec<u8> = vec![
+            255, 0, 0, 255, // red
+            0, 255, 0, 255, // green
+            0, 0, 255, 255, // blue
+            255, 255, 255, 255, // white
+        ];
+        let image = ImageData {
+            width: 2,
+            height: 2,
+            bytes: Cow::Owned(bytes.clone()),
+        };
+
+        let png = encode_png(&image).expect("encode");
+        let decoded = decode_png(&png).expect("decode");
+
+        assert_eq!(decoded.width, 2);
+        assert_eq!(decoded.height, 2);
+        assert_eq!(decoded.bytes.as_ref(), bytes.as_slice());
+    }
+}